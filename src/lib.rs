@@ -14,8 +14,13 @@ impl Plugin for CarPlugin {
     fn build(&self, app: &mut App) {
         app.add_startup_system(setup_car)
             .add_system(update_velocity)
+            .add_system(drive_pacer)
             .add_system(gamepad_connections)
-            .add_system(control_throttle);
+            .add_system(control_throttle)
+            .add_system(control_steering)
+            .add_system(control_transmission)
+            .add_system(control_traction)
+            .add_system(control_handbrake);
     }
 }
 
@@ -40,6 +45,28 @@ struct Model {
     engine_torque: Vec<(f32, f32)>,
     /// Constant for brake force, I have no idea how to find out what it should be.
     brakes: f32,
+    /// Maximum steering angle at full lock, in radians.
+    steer_lock: f32,
+    /// Distance between the front and rear axles, in metres.
+    wheelbase: f32,
+    /// Gear ratio used for reverse.
+    reverse_ratio: f32,
+    /// Flywheel moment of inertia: how sluggishly engine rpm follows a change
+    /// in torque. Higher values rev up and down more slowly.
+    engine_inertia: f32,
+    /// Rev limiter cutoff, in RPM.
+    max_rpm: f32,
+    /// Length of the car, in metres; sets the scale of the draft zone.
+    car_length: f32,
+    /// Peak tire friction coefficient (longitudinal grip at the ideal slip
+    /// ratio), before the fitted tire compound's own multiplier.
+    tire_grip: f32,
+    /// Effective inertia of the driven wheel, governing how fast surplus
+    /// engine torque spins it up during wheelspin.
+    wheel_inertia: f32,
+    /// Constant for handbrake braking force, scaled by how far the handbrake
+    /// is applied and independent of the footbrake.
+    handbrake_force: f32,
 }
 
 impl Model {
@@ -80,32 +107,114 @@ lazy_static! {
             (5800.0, 450.0)
         ],
         brakes: 12000.0,
+        steer_lock: 0.6,
+        wheelbase: 2.685,
+        reverse_ratio: 2.90,
+        engine_inertia: 10.0,
+        max_rpm: 6500.0,
+        car_length: 4.46,
+        tire_grip: 1.05,
+        wheel_inertia: 1.2,
+        handbrake_force: 16000.0,
     };
 }
 
+/// Tire compound fitted to the car; changes overall grip relative to the
+/// model's base `tire_grip`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TireCompound {
+    Soft,
+    Medium,
+    Hard,
+}
+
+impl TireCompound {
+    /// Grip multiplier relative to the model's base `tire_grip`.
+    fn grip_multiplier(self) -> f32 {
+        match self {
+            TireCompound::Soft => 1.1,
+            TireCompound::Medium => 1.0,
+            TireCompound::Hard => 0.9,
+        }
+    }
+}
+
+impl Default for TireCompound {
+    fn default() -> Self {
+        TireCompound::Medium
+    }
+}
+
+/// Transmission mode. Automatic picks its own gear like before; manual hands
+/// gear and clutch control to the driver, money-shifts and stalls included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transmission {
+    Automatic,
+    Manual,
+}
+
+impl Default for Transmission {
+    fn default() -> Self {
+        Transmission::Automatic
+    }
+}
+
 /// An actual moving car
 #[derive(Component, Default)]
 pub struct Car {
     /// Direction (constant for now, we don't have a steering wheel).
     direction: Vec2,
+    /// Position on the track, in metres.
+    position: Vec2,
     /// Velocity vector.
     velocity: Vec2,
     /// Speed in M/S.
     pub speed: f32,
     /// Throttle 0.0 no throttle, 1.0 full power, -1.0 full brakes.
     throttle: f32,
-    /// Current gear.
+    /// Steering input, -1.0 full left, 1.0 full right, 0.0 centered.
+    steer: f32,
+    /// Current gear. 0 is reverse, 1 is first, and so on.
     pub gear: usize,
-    /// Current RPM.
+    /// Current RPM. In manual mode this is the engine's own angular state,
+    /// integrated via flywheel inertia rather than derived from wheel speed.
     pub rpm: f32,
     /// Keep track of which speed we geared up, from 2nd gear upward, we just gear down when reaching this speed when braking.
     speeds: Vec<f32>,
+    /// Automatic or manual gearbox.
+    pub transmission: Transmission,
+    /// Clutch engagement: 0.0 fully disengaged (engine free-revving), 1.0
+    /// fully locked to the drivetrain.
+    clutch: f32,
+    /// Aerodynamic drag multiplier from drafting another car, 1.0 meaning
+    /// no reduction at all. Exposed so the HUD can show when we're in the tow.
+    pub draft_multiplier: f32,
+    /// Tire compound fitted to the car.
+    pub tire_compound: TireCompound,
+    /// Driven wheel's own surface speed (wheel radius times angular speed),
+    /// tracked separately from chassis speed so it can spin faster than the
+    /// car under wheelspin.
+    wheel_surface_speed: f32,
+    /// When enabled, momentarily cuts throttle once slip passes its peak.
+    pub traction_control: bool,
+    /// Whether the driven wheel is currently spinning faster than grip
+    /// allows. Exposed so the HUD can show a wheelspin warning.
+    pub wheelspin: bool,
+    /// Handbrake engagement, 0.0 released to 1.0 fully applied. Exposed so a
+    /// future steering/traction system can trigger rear-wheel lockup and
+    /// reduced lateral grip from it.
+    pub handbrake: f32,
 }
 
 /// Mark the car the player operates.
 #[derive(Component)]
 pub struct Player {}
 
+/// Mark a car that just cruises ahead at a constant throttle, so the player
+/// has someone to draft behind without needing a second controller.
+#[derive(Component)]
+struct Pacer;
+
 /// Setup the starting state of the car, not moving, in first gear.
 fn setup_car(mut commands: Commands) {
     commands.spawn((
@@ -113,10 +222,35 @@ fn setup_car(mut commands: Commands) {
         Car {
             direction: Vec2::X,
             gear: 1,
+            clutch: 1.0,
+            draft_multiplier: 1.0,
             ..Car::default()
         },
         CORVETTE.clone(),
     ));
+    // A pacer car, out ahead of the player, exercises the multi-car drafting
+    // in `update_velocity` outside of the unit tests too.
+    commands.spawn((
+        Pacer,
+        Car {
+            direction: Vec2::X,
+            position: Vec2::new(30.0, 0.0),
+            gear: 3,
+            clutch: 1.0,
+            throttle: 0.3,
+            draft_multiplier: 1.0,
+            ..Car::default()
+        },
+        CORVETTE.clone(),
+    ));
+}
+
+/// Keep the pacer car rolling forward at a steady throttle; it isn't driven
+/// by any input, just there to be drafted.
+fn drive_pacer(mut cars: Query<&mut Car, With<Pacer>>) {
+    for mut car in &mut cars {
+        car.throttle = 0.3;
+    }
 }
 
 /// Lookup the torque for the given engine rpm.
@@ -138,72 +272,317 @@ fn wheel_speed(car: &Car, model: &Model) -> f32 {
     car.speed / model.wheel_radius
 }
 
-/// Engine rpm based on the current wheel speed of the given car.
+/// Gear ratio for the car's current gear; gear 0 is reverse.
+fn gear_ratio(car: &Car, model: &Model) -> f32 {
+    if car.gear == 0 {
+        model.reverse_ratio
+    } else {
+        model.gear_ratios[car.gear - 1]
+    }
+}
+
+/// Engine rpm the wheel speed would drive, in the current gear, were the
+/// clutch fully locked.
 fn engine_rpm(car: &Car, model: &Model, wheel_speed: f32) -> f32 {
-    wheel_speed * model.gear_ratios[car.gear - 1] * model.differential_ratio * 60.0 / (2.0 * PI)
+    wheel_speed * gear_ratio(car, model) * model.differential_ratio * 60.0 / (2.0 * PI)
+}
+
+/// Slip ratio where peak tire grip is reached, give or take.
+const PEAK_SLIP: f32 = 0.06;
+/// Acceleration of gravity, for converting tire grip into a traction force.
+const G: f32 = 9.81;
+/// Below this chassis speed the car is essentially stationary, so there's no
+/// meaningful slip to measure yet; treat the tires as full static friction
+/// instead of running them through the slip curve, or the car could never
+/// get moving from a dead stop.
+const STATIC_GRIP_SPEED: f32 = 0.5;
+/// Speed range, past `STATIC_GRIP_SPEED`, over which grip blends from the
+/// static-friction value to whatever `grip_curve` gives at the current slip.
+/// Without this the cap on transmitted force could fall off a cliff the
+/// instant the car crosses `STATIC_GRIP_SPEED` at near-zero slip, dumping
+/// almost all the engine's torque into wheelspin in a single frame.
+const GRIP_BLEND_SPEED: f32 = 2.0;
+/// A sane upper bound on how fast the driven wheel's surface speed can
+/// change in a single frame, regardless of gearing, so a pathological
+/// combination of torque and ratio can never slam it to an unrecoverable
+/// spin in one step.
+const MAX_WHEEL_ACCEL: f32 = 50.0;
+/// At or below this rpm the engine counts as stalled rather than merely
+/// idling low, and the starter can bump it back up to idle.
+const STALL_RPM: f32 = 1.0;
+
+/// Longitudinal slip ratio between the driven wheel's own surface speed and
+/// the chassis speed.
+fn slip_ratio(car: &Car) -> f32 {
+    (car.wheel_surface_speed - car.speed) / car.speed.max(0.01)
+}
+
+/// Simplified Pacejka-like grip curve: rises to a peak at `PEAK_SLIP`, then
+/// falls off either side of it.
+fn grip_curve(slip: f32) -> f32 {
+    let s = slip.abs() / PEAK_SLIP;
+    if s <= 1.0 {
+        s
+    } else {
+        1.0 / s
+    }
+}
+
+/// How much to reduce a trailing car's drag when drafting a leader ahead,
+/// Speed Dreams' aero update style: the leader must be almost directly
+/// ahead, headings roughly aligned, and close enough, relative to car
+/// length, for the tow to matter. Returns `None` if any of that doesn't hold.
+fn draft_reduction(car: &Car, leader_pos: Vec2, leader_dir: Vec2, model: &Model) -> Option<f32> {
+    let to_leader = leader_pos - car.position;
+    let distance = to_leader.length();
+    if distance <= f32::EPSILON {
+        return None;
+    }
+    // Headings must be roughly aligned, i.e. the leader isn't crossing us.
+    if car.direction.angle_between(leader_dir).abs() > (8.0_f32).to_radians() {
+        return None;
+    }
+    // The leader must be almost directly ahead, not off to a side.
+    if car.direction.angle_between(to_leader).abs() > (10.0_f32).to_radians() {
+        return None;
+    }
+    let draft_range = model.car_length * 10.0;
+    if distance > draft_range {
+        return None;
+    }
+    // Taken literally, `reduction = 1 - exp(-k * distance)` grows *with*
+    // distance, i.e. the tow would get stronger the further back you sit,
+    // which contradicts the draft zone cutoff right above it and isn't how
+    // a slipstream behaves. We apply that same decay shape to `closeness`
+    // (how far into the zone we are, `draft_range - distance`) instead, so
+    // reduction is strongest tucked in close and fades out towards the
+    // zone's edge; scaled by the zone size so the falloff actually spans it
+    // instead of saturating within the first few metres. Capped below 1.0:
+    // even tucked right on someone's bumper some drag always remains.
+    let closeness = draft_range - distance;
+    let decay = 3.0 / draft_range;
+    Some((1.0 - (-decay * closeness).exp()).clamp(0.0, 0.9))
+}
+
+/// Normalize an angle into the `[-PI, PI]` range, TORCS' `NORM_PI_PI` style,
+/// so a heading doesn't accumulate unbounded as the car keeps turning.
+fn norm_pi_pi(mut angle: f32) -> f32 {
+    while angle > PI {
+        angle -= 2.0 * PI;
+    }
+    while angle < -PI {
+        angle += 2.0 * PI;
+    }
+    angle
 }
 
 /// Update the velocity and speed of the car.
 /// <https://asawicki.info/Mirror/Car%20Physics%20for%20Games/Car%20Physics%20for%20Games.html>
-fn update_velocity(time: Res<Time>, mut cars: Query<(&mut Car, &Model), With<Player>>) {
-    for (mut car, model) in &mut cars {
-        // Update rpm based on wheel speed.
-        car.rpm = engine_rpm(&car, model, wheel_speed(&car, model));
-        //println!("rpm: {:.2}",car.rpm);
+fn update_velocity(time: Res<Time>, mut cars: Query<(Entity, &mut Car, &Model)>) {
+    // Snapshot every car's position and heading before anyone moves this
+    // frame, so drafting compares against where the pack actually was.
+    let snapshot: Vec<(Entity, Vec2, Vec2)> = cars
+        .iter()
+        .map(|(entity, car, _)| (entity, car.position, car.direction))
+        .collect();
+
+    for (entity, mut car, model) in &mut cars {
+        // Drafting: look for a leader ahead of us and reduce our drag.
+        let mut draft_multiplier = 1.0_f32;
+        for &(other_entity, other_position, other_direction) in &snapshot {
+            if other_entity == entity {
+                continue;
+            }
+            if let Some(reduction) = draft_reduction(&car, other_position, other_direction, model)
+            {
+                draft_multiplier = draft_multiplier.min(1.0 - reduction);
+            }
+        }
+        car.draft_multiplier = draft_multiplier;
+
+        // Wheel-driven rpm in the current gear, i.e. what the engine would be
+        // doing if fully locked to the drivetrain through the clutch. Uses
+        // the driven wheel's own surface speed, so wheelspin feeds into rpm.
+        let driven_rpm = engine_rpm(&car, model, car.wheel_surface_speed / model.wheel_radius);
         let min = model.min_rpm();
         let best = model.best_rpm();
-        // Sanity.
-        if car.rpm < min {
-            car.rpm = min;
-        // Switch gear,
-        } else if car.rpm >= best && car.gear < model.gear_ratios.len() {
-            //println!("{:.2}",car.rpm);
-            car.rpm = min;
-            car.gear += 1;
-            let g = car.gear;
-            // Keep track of the speeds we switched gears on.
-            if g > 1 {
-                let s = car.speed;
-                if car.speeds.len() < g - 1 {
-                    car.speeds.push(s);
+        // Torque the flywheel itself soaks up accelerating its own rpm
+        // (GTA's m_fEngineInertia); only Manual spins the rpm up freely
+        // enough for this to matter, so it stays zero otherwise.
+        let mut flywheel_absorbed_torque = 0.0_f32;
+        match car.transmission {
+            Transmission::Automatic => {
+                // Always locked, and the gearbox shifts itself, as before.
+                car.clutch = 1.0;
+                car.rpm = driven_rpm;
+                // Sanity.
+                if car.rpm < min {
+                    car.rpm = min;
+                // Switch gear,
+                } else if car.rpm >= best && car.gear < model.gear_ratios.len() {
+                    car.rpm = min;
+                    car.gear += 1;
+                    let g = car.gear;
+                    // Keep track of the speeds we switched gears on.
+                    if g > 1 {
+                        let s = car.speed;
+                        if car.speeds.len() < g - 1 {
+                            car.speeds.push(s);
+                        } else {
+                            car.speeds[g - 2] = s;
+                        }
+                    }
+                }
+            }
+            Transmission::Manual => {
+                // Flywheel inertia (mirroring GTA's m_fEngineInertia): with
+                // the clutch open the engine only answers to throttle, capped
+                // by the rev limiter; closing the clutch pulls it towards the
+                // wheel-driven rpm instead, at a rate set by how locked it is.
+                let max_torque = lookup_torque(model, car.rpm);
+                let free_rev_rpm = if car.throttle > 0.0 {
+                    car.rpm + max_torque * car.throttle / model.engine_inertia * time.delta_seconds()
                 } else {
-                    car.speeds[g - 2] = s;
+                    car.rpm - max_torque / model.engine_inertia * time.delta_seconds()
                 }
+                .clamp(0.0, model.max_rpm);
+                let new_rpm = (free_rev_rpm + (driven_rpm - free_rev_rpm) * car.clutch)
+                    .clamp(0.0, model.max_rpm);
+                // The torque spent accelerating the flywheel to new_rpm isn't
+                // available to drive the wheels; dumping the clutch at low
+                // wheel speed can even stall the engine now that rpm is free
+                // to fall all the way to zero.
+                flywheel_absorbed_torque =
+                    model.engine_inertia * (new_rpm - car.rpm) / time.delta_seconds();
+                car.rpm = new_rpm;
             }
         }
+        // Handbrake forces the drivetrain open so revving it doesn't stall
+        // the engine, regardless of transmission mode.
+        if car.handbrake > 0.0 {
+            car.clutch *= 1.0 - car.handbrake;
+        }
+        // Steering: a simple bicycle model turn radius from the steer angle,
+        // giving a yaw rate that we use to rotate direction and velocity.
+        let steer_angle = car.steer * model.steer_lock;
+        let omega = if car.speed > 0.01 && steer_angle != 0.0 {
+            let turn_radius = model.wheelbase / steer_angle.sin();
+            car.speed / turn_radius
+        } else {
+            // Parked, or wheels dead ahead: no yaw rate, so a parked car
+            // can't spin in place and we avoid a division by a tiny speed.
+            0.0
+        };
+        if omega != 0.0 {
+            let delta_heading = omega * time.delta_seconds();
+            let heading = norm_pi_pi(car.direction.y.atan2(car.direction.x) + delta_heading);
+            car.direction = Vec2::from_angle(heading);
+            car.velocity = Vec2::from_angle(delta_heading).rotate(car.velocity);
+        }
+
         // Forced applied by the player.
         let control = if car.throttle > 0.0 {
             // Acceleration.
             //println!("rpm: {rpm} gear: {}", car.gear);
             let max_torque = lookup_torque(model, car.rpm);
-            let engine_torque = max_torque * car.throttle;
+            let slip = slip_ratio(&car);
+            car.wheelspin = slip > PEAK_SLIP;
+            // Traction control momentarily cuts throttle once slip passes
+            // the peak, matching the slip limits in the Redline controls.
+            let effective_throttle = if car.traction_control && car.wheelspin {
+                0.0
+            } else {
+                car.throttle
+            };
+            // Torque absorbed revving up the flywheel isn't available to
+            // drive the wheels.
+            let engine_torque =
+                (max_torque * effective_throttle - flywheel_absorbed_torque).max(0.0);
             //let traction = car.direction * car.engine_force;
-            car.direction
-                * engine_torque
-                * model.gear_ratios[car.gear - 1]
+            // Reverse gear spins the output the other way; the torque that
+            // reaches the wheels scales with clutch engagement.
+            let gear_direction = if car.gear == 0 {
+                -car.direction
+            } else {
+                car.direction
+            };
+            let requested = engine_torque
+                * gear_ratio(&car, model)
                 * model.differential_ratio
                 * model.transmission_efficiency
-                / model.wheel_radius
+                * car.clutch
+                / model.wheel_radius;
+            // Cap what the tires can actually put down; surplus torque spins
+            // the driven wheel instead of accelerating the car (wheelspin).
+            // Below `STATIC_GRIP_SPEED` there's essentially no chassis speed
+            // to measure slip against, so give the tires full static
+            // friction instead of running them through the slip curve; past
+            // that we blend towards the slip curve's own value over
+            // `GRIP_BLEND_SPEED` rather than switching straight over, or
+            // crossing the threshold at near-zero slip would hand almost all
+            // the engine's torque to wheelspin in a single frame.
+            let grip_available = if car.speed < STATIC_GRIP_SPEED {
+                1.0
+            } else {
+                let blend = ((car.speed - STATIC_GRIP_SPEED) / GRIP_BLEND_SPEED).clamp(0.0, 1.0);
+                1.0 + (grip_curve(slip) - 1.0) * blend
+            };
+            let grip_limit = model.tire_grip * car.tire_compound.grip_multiplier() * model.mass * G;
+            let transmitted = requested.min(grip_limit * grip_available);
+            let surplus = (requested - transmitted).max(0.0);
+            // Surplus is a force at the contact patch, not a torque, so it
+            // takes a wheel_radius^2 to turn it into an angular acceleration
+            // of the wheel via its moment of inertia. Crucially that inertia
+            // isn't just the bare wheel: through a locked clutch the engine
+            // and driveline spin with it, reflected through the overall
+            // gearing (GTA-style reflected inertia, I_eng * (gear*diff)^2),
+            // which dominates at low gears and is what actually keeps
+            // wheelspin bounded and recoverable instead of blowing up.
+            let overall_ratio = gear_ratio(&car, model) * model.differential_ratio;
+            let effective_inertia =
+                model.wheel_inertia + model.engine_inertia * overall_ratio * overall_ratio;
+            let wheel_accel = (surplus * model.wheel_radius * model.wheel_radius
+                / effective_inertia)
+                .min(MAX_WHEEL_ACCEL);
+            car.wheel_surface_speed += wheel_accel * time.delta_seconds();
+            // Grip (full or reduced by slip) constantly drags the wheel's
+            // surface speed back towards the chassis speed, same as a real
+            // tire scrubbing off excess spin; without this, a spike in
+            // wheelspin would never recover and traction control would cut
+            // throttle forever once triggered.
+            let relax = (car.wheel_surface_speed - car.speed) * grip_available.max(0.2) * 4.0;
+            car.wheel_surface_speed -= relax * time.delta_seconds();
+            gear_direction * transmitted
             // Brakes.
         } else if car.throttle < 0.0 {
+            // No driving torque, so the wheel just follows the chassis.
+            car.wheel_surface_speed = car.speed;
+            car.wheelspin = false;
             car.direction * car.throttle * model.brakes
         } else {
             // Nothing.
+            car.wheel_surface_speed = car.speed;
+            car.wheelspin = false;
             Vec2::ZERO
         };
         //println!("{control}");
-        // Drag.
-        let drag = -model.drag * car.velocity * car.speed;
+        // Drag, reduced if we're drafting another car.
+        let drag = -model.drag * car.draft_multiplier * car.velocity * car.speed;
         // Rolling resistance.
         let rolling_resistance = -model.rolling_resistance * car.velocity;
         //println!("traction: {traction}, drag: {drag}, rr: {rolling_resistance}");
+        // Handbrake: a strong braking force independent of the footbrake,
+        // opposing whichever way the car is actually moving.
+        let handbrake_force =
+            -car.velocity.normalize_or_zero() * model.handbrake_force * car.handbrake;
         // Full longitudinal force.
-        let longitudinal = control + drag + rolling_resistance;
+        let longitudinal = control + drag + rolling_resistance + handbrake_force;
         // Acceleration.
         let acceleration = longitudinal / model.mass;
         // Current velocity and speed.
         car.velocity += acceleration * time.delta_seconds();
         car.speed = car.velocity.length();
+        car.position += car.velocity * time.delta_seconds();
         //println!("{}",car.velocity.angle_between(car.direction) > 90.0 * PI / 180.0);
         //println!("{} {}", car.velocity,car.velocity.angle_between(car.direction));
 
@@ -218,9 +597,14 @@ fn update_velocity(time: Res<Time>, mut cars: Query<(&mut Car, &Model), With<Pla
                 if car.throttle < 0.0 {
                     car.throttle = 0.0
                 }
-                car.gear = 1;
+                // Automatic always comes to rest in first; manual keeps
+                // whatever gear the driver left it in.
+                if car.transmission == Transmission::Automatic {
+                    car.gear = 1;
+                }
                 // Downgear.
-            } else if car.gear > 1
+            } else if car.transmission == Transmission::Automatic
+                && car.gear > 1
                 && car.speeds.len() > car.gear - 2
                 && car.speed < car.speeds[car.gear - 2]
             {
@@ -323,6 +707,208 @@ fn control_throttle(
     }
 }
 
+/// Steer control.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Steer {
+    Left,
+    Right,
+    Center,
+}
+
+/// Control the steering based on input, ramping toward the target steer
+/// value exactly like the throttle, but faster to release than to apply.
+fn control_steering(
+    time: Res<Time>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+    gamepad_axes: Option<Res<Axis<GamepadAxis>>>,
+    my_gamepad: Option<Res<MyGamepad>>,
+    mut cars: Query<&mut Car, With<Player>>,
+) {
+    if let Ok(mut car) = cars.get_single_mut() {
+        let mut steer = Steer::Center;
+        // Keyboard left/right.
+        if let Some(input) = &keyboard {
+            if input.pressed(KeyCode::Right) && car.steer < 1.0 {
+                steer = Steer::Right;
+            } else if input.pressed(KeyCode::Left) && car.steer > -1.0 {
+                steer = Steer::Left;
+            }
+        }
+        // If the keyboard isn't steering, check the gamepad left stick.
+        if steer == Steer::Center {
+            if let Some(axes) = &gamepad_axes {
+                if let Some(gp) = &my_gamepad {
+                    let stick_x = axes
+                        .get(GamepadAxis {
+                            gamepad: gp.0,
+                            axis_type: GamepadAxisType::LeftStickX,
+                        })
+                        .unwrap_or(0.0);
+                    if stick_x > 0.1 && car.steer < 1.0 {
+                        steer = Steer::Right;
+                    } else if stick_x < -0.1 && car.steer > -1.0 {
+                        steer = Steer::Left;
+                    }
+                }
+            }
+        }
+
+        // Apply over ~0.6s, release faster (~0.2s) so the wheel snaps back.
+        match steer {
+            Steer::Right => {
+                let mut s = car.steer.max(0.0);
+                s += time.delta_seconds() / 0.6;
+                car.steer = s.min(1.0);
+            }
+            Steer::Left => {
+                let mut s = car.steer.min(0.0);
+                s -= time.delta_seconds() / 0.6;
+                car.steer = s.max(-1.0);
+            }
+            Steer::Center => {
+                let release = time.delta_seconds() / 0.2;
+                if car.steer > 0.0 {
+                    car.steer = (car.steer - release).max(0.0);
+                } else if car.steer < 0.0 {
+                    car.steer = (car.steer + release).min(0.0);
+                }
+            }
+        }
+    }
+}
+
+/// Shift gears and work the clutch in manual mode; automatic mode ignores
+/// this input entirely and keeps shifting itself.
+fn control_transmission(
+    time: Res<Time>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+    gamepad_buttons: Option<Res<Input<GamepadButton>>>,
+    my_gamepad: Option<Res<MyGamepad>>,
+    mut cars: Query<(&mut Car, &Model), With<Player>>,
+) {
+    if let Ok((mut car, model)) = cars.get_single_mut() {
+        if car.transmission != Transmission::Manual {
+            return;
+        }
+
+        let mut shift_up = false;
+        let mut shift_down = false;
+        let mut clutch_in = false;
+        if let Some(input) = &keyboard {
+            shift_up |= input.just_pressed(KeyCode::E);
+            shift_down |= input.just_pressed(KeyCode::Q);
+            clutch_in |= input.pressed(KeyCode::LShift);
+        }
+        if let Some(input) = &gamepad_buttons {
+            if let Some(gp) = &my_gamepad {
+                let gamepad = gp.0;
+                shift_up |= input.just_pressed(GamepadButton {
+                    gamepad,
+                    button_type: GamepadButtonType::RightTrigger,
+                });
+                shift_down |= input.just_pressed(GamepadButton {
+                    gamepad,
+                    button_type: GamepadButtonType::LeftTrigger,
+                });
+                clutch_in |= input.pressed(GamepadButton {
+                    gamepad,
+                    button_type: GamepadButtonType::West,
+                });
+            }
+        }
+
+        // Gear 0 is reverse; top gear is capped by the model.
+        if shift_up && car.gear < model.gear_ratios.len() {
+            car.gear += 1;
+        } else if shift_down && car.gear > 0 {
+            car.gear -= 1;
+        }
+
+        // Clutch pedal in disengages fast, releasing lets it bite back in
+        // more gradually, like letting a real clutch pedal up.
+        if clutch_in {
+            car.clutch = (car.clutch - time.delta_seconds() / 0.15).max(0.0);
+        } else {
+            car.clutch = (car.clutch + time.delta_seconds() / 0.3).min(1.0);
+        }
+
+        // Starter: bump a stalled engine (rpm at or below `STALL_RPM`) back
+        // up to idle.
+        let mut starter = false;
+        if let Some(input) = &keyboard {
+            starter |= input.just_pressed(KeyCode::R);
+        }
+        if let Some(input) = &gamepad_buttons {
+            if let Some(gp) = &my_gamepad {
+                starter |= input.just_pressed(GamepadButton {
+                    gamepad: gp.0,
+                    button_type: GamepadButtonType::East,
+                });
+            }
+        }
+        if starter && car.rpm <= STALL_RPM {
+            car.rpm = model.min_rpm();
+        }
+    }
+}
+
+/// Toggle traction control on keyboard `T` or gamepad North button press.
+fn control_traction(
+    keyboard: Option<Res<Input<KeyCode>>>,
+    gamepad_buttons: Option<Res<Input<GamepadButton>>>,
+    my_gamepad: Option<Res<MyGamepad>>,
+    mut cars: Query<&mut Car, With<Player>>,
+) {
+    if let Ok(mut car) = cars.get_single_mut() {
+        let mut toggle = false;
+        if let Some(input) = &keyboard {
+            toggle |= input.just_pressed(KeyCode::T);
+        }
+        if let Some(input) = &gamepad_buttons {
+            if let Some(gp) = &my_gamepad {
+                toggle |= input.just_pressed(GamepadButton {
+                    gamepad: gp.0,
+                    button_type: GamepadButtonType::North,
+                });
+            }
+        }
+        if toggle {
+            car.traction_control = !car.traction_control;
+        }
+    }
+}
+
+/// Control the handbrake: spacebar or a gamepad button, ramped over ~0.2s to
+/// apply but released near-instantly, unlike the footbrake's smooth ramp.
+fn control_handbrake(
+    time: Res<Time>,
+    keyboard: Option<Res<Input<KeyCode>>>,
+    gamepad_buttons: Option<Res<Input<GamepadButton>>>,
+    my_gamepad: Option<Res<MyGamepad>>,
+    mut cars: Query<&mut Car, With<Player>>,
+) {
+    if let Ok(mut car) = cars.get_single_mut() {
+        let mut engaged = false;
+        if let Some(input) = &keyboard {
+            engaged |= input.pressed(KeyCode::Space);
+        }
+        if let Some(input) = &gamepad_buttons {
+            if let Some(gp) = &my_gamepad {
+                engaged |= input.pressed(GamepadButton {
+                    gamepad: gp.0,
+                    button_type: GamepadButtonType::South,
+                });
+            }
+        }
+        if engaged {
+            car.handbrake = (car.handbrake + time.delta_seconds() / 0.2).min(1.0);
+        } else {
+            // Releases near-instantly, unlike the ~0.2s it takes to apply.
+            car.handbrake = (car.handbrake - time.delta_seconds() / 0.02).max(0.0);
+        }
+    }
+}
+
 // The gamepad used by the player.
 #[derive(Resource)]
 struct MyGamepad(Gamepad);
@@ -416,4 +1002,55 @@ mod tests {
         assert_abs_diff_eq!(475.0, lookup_torque(&CORVETTE, 5400.0));
         assert_abs_diff_eq!(450.0, lookup_torque(&CORVETTE, 5800.0));
     }
+
+    #[test]
+    fn test_norm_pi_pi() {
+        assert_abs_diff_eq!(0.0, norm_pi_pi(0.0));
+        assert_abs_diff_eq!(PI - 0.1, norm_pi_pi(PI - 0.1));
+        assert_abs_diff_eq!(-PI + 0.1, norm_pi_pi(PI + 0.1));
+        assert_abs_diff_eq!(0.1, norm_pi_pi(2.0 * PI + 0.1));
+        assert_abs_diff_eq!(-0.1, norm_pi_pi(-2.0 * PI - 0.1));
+    }
+
+    #[test]
+    fn test_draft_reduction() {
+        let trailing = Car {
+            direction: Vec2::X,
+            position: Vec2::ZERO,
+            ..Car::default()
+        };
+        // Directly ahead, same heading, well within the draft zone: towed.
+        assert!(draft_reduction(&trailing, Vec2::new(5.0, 0.0), Vec2::X, &CORVETTE).is_some());
+        // Same line but far beyond the draft zone: no tow.
+        assert!(draft_reduction(&trailing, Vec2::new(1000.0, 0.0), Vec2::X, &CORVETTE).is_none());
+        // Off to the side rather than ahead: no tow.
+        assert!(draft_reduction(&trailing, Vec2::new(0.0, 5.0), Vec2::X, &CORVETTE).is_none());
+    }
+
+    #[test]
+    fn test_grip_curve() {
+        assert_abs_diff_eq!(0.0, grip_curve(0.0));
+        assert_abs_diff_eq!(1.0, grip_curve(PEAK_SLIP));
+        assert_abs_diff_eq!(0.5, grip_curve(2.0 * PEAK_SLIP));
+    }
+
+    #[test]
+    fn test_slip_ratio() {
+        let car = Car {
+            speed: 10.0,
+            wheel_surface_speed: 11.0,
+            ..Car::default()
+        };
+        assert_abs_diff_eq!(0.1, slip_ratio(&car));
+    }
+
+    #[test]
+    fn test_gear_ratio_reverse() {
+        let car = Car {
+            direction: Vec2::X,
+            gear: 0,
+            ..Car::default()
+        };
+        assert_abs_diff_eq!(CORVETTE.reverse_ratio, gear_ratio(&car, &CORVETTE));
+    }
 }
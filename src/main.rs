@@ -10,6 +10,7 @@ fn main() {
         .add_system(update_speed)
         .add_system(show_gear)
         .add_system(show_rpm)
+        .add_system(show_draft)
         .run();
 }
 
@@ -66,6 +67,19 @@ fn setup_graphics(mut commands: Commands, asset_server: Res<AssetServer>) {
                 .with_text_alignment(TextAlignment::Center),
                 RpmDial,
             ));
+
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraMono-Medium.ttf"),
+                        font_size: 60.0,
+                        color: Color::GOLD,
+                    },
+                )
+                .with_text_alignment(TextAlignment::Center),
+                DraftDial,
+            ));
         });
 
     commands.spawn(SpriteBundle {
@@ -91,6 +105,10 @@ struct GearDial;
 #[derive(Component)]
 struct RpmDial;
 
+/// Mark the draft/tow indicator text bundle.
+#[derive(Component)]
+struct DraftDial;
+
 /// Show the current speed.
 fn update_speed(
     mut speed_text: Query<&mut Text, With<SpeedDial>>,
@@ -120,3 +138,16 @@ fn show_rpm(mut speed_text: Query<&mut Text, With<RpmDial>>, cars: Query<&Car, W
         }
     }
 }
+
+/// Show whether the player is currently drafting another car.
+fn show_draft(mut draft_text: Query<&mut Text, With<DraftDial>>, cars: Query<&Car, With<Player>>) {
+    if let Ok(car) = cars.get_single() {
+        for mut text in &mut draft_text {
+            text.sections[0].value = if car.draft_multiplier < 1.0 {
+                format!("IN THE TOW ({:.0}%)", car.draft_multiplier * 100.0)
+            } else {
+                String::new()
+            };
+        }
+    }
+}